@@ -1,32 +1,106 @@
 #![crate_name = "pond_cache"]
 
 use std::hash::{DefaultHasher, Hash, Hasher};
-use std::path::PathBuf;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use chrono::{DateTime, Duration, Utc};
+use lru::LruCache;
 use rusqlite::Connection;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 pub use rusqlite::Error;
 
+/// Policy controlling what happens when the cache's SQLite connection cannot
+/// be established (the file can't be opened, or the schema can't be created)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CacheFailure {
+    /// Return the underlying error, as if no policy had been set
+    #[default]
+    Error,
+    /// Fall back to an in-memory database, so the cache keeps working for
+    /// the lifetime of the process, just without persistence
+    InMemory,
+    /// Fall back to a no-op cache: `store` silently succeeds and `get`
+    /// always returns `Ok(None)`
+    Blackhole,
+}
+
 /// Pond cache struct
 pub struct Cache<T> {
     path: PathBuf,
     ttl: Duration,
+    conn: Option<Mutex<Connection>>,
+    lru: Option<Mutex<LruCache<Vec<u8>, LruEntry<T>>>>,
     data: std::marker::PhantomData<T>,
 }
 
+#[derive(Clone)]
+struct LruEntry<T> {
+    value: T,
+    expiration: DateTime<Utc>,
+}
+
+// `id` (the key's hash) is not unique on its own: two distinct keys can
+// collide on their 64-bit hash, so the primary key also includes `key` (the
+// canonical key bytes) to let colliding keys coexist as separate rows
+// instead of one overwriting the other's row on `INSERT OR REPLACE`.
+const CREATE_TABLE_SQL: &str = "CREATE TABLE IF NOT EXISTS items (
+    id      INTEGER NOT NULL,
+    key     BLOB NOT NULL,
+    expires TEXT NOT NULL,
+    data    BLOB NOT NULL,
+    PRIMARY KEY (id, key)
+)";
+
+/// Maximum number of keys looked up in a single `WHERE id IN (...)` query
+/// issued by `get_many`, kept comfortably under SQLite's bound parameter
+/// limit (999 by default, configurable up to 32766) so a large batch can't
+/// fail the entire lookup with `SqlInputError`.
+const GET_MANY_CHUNK_SIZE: usize = 500;
+
+/// A key usable with [`Cache`]
+///
+/// Keys are looked up by hash, but the hash alone is only 64 bits, so the
+/// canonical bytes returned by `key_bytes` are stored alongside it and
+/// compared against on every read and write to reject hash collisions
+/// instead of silently returning, or overwriting, the wrong value.
+pub trait CacheKey: Hash {
+    /// Canonical byte representation of the key, persisted so a collision
+    /// on the hash can be detected
+    fn key_bytes(&self) -> Vec<u8>;
+}
+
+/// Blanket impl covering any `Hash`-able, serializable key, canonicalizing
+/// it to bytes via the same `bitcode` encoding used for cached values, so
+/// integers, tuples, and `#[derive(Serialize, Hash)]` structs all work as
+/// keys without a hand-written impl.
+impl<K: Hash + Serialize> CacheKey for K {
+    fn key_bytes(&self) -> Vec<u8> {
+        bitcode::serialize(self).unwrap()
+    }
+}
+
 #[derive(Debug)]
 struct CacheEntry<T>
 where
     T: Serialize + DeserializeOwned + Clone,
 {
-    key: u32,
+    key: u64,
+    key_bytes: Vec<u8>,
     value: T,
     expiration: DateTime<Utc>,
 }
 
+/// A row fetched from `items` by `get_many`, keyed by its stored `id`.
+struct StoredRow {
+    key_bytes: Vec<u8>,
+    expires: DateTime<Utc>,
+    data: Vec<u8>,
+}
+
 impl<T: Serialize + DeserializeOwned + Clone> Cache<T> {
     /// Create a new cache with a default time-to-live of 10 minutes
     ///
@@ -71,24 +145,191 @@ impl<T: Serialize + DeserializeOwned + Clone> Cache<T> {
     /// let cache: Cache<String> = Cache::with_time_to_live(PathBuf::from("cache.db"), Duration::minutes(5)).expect("Failed to create cache");
     /// ```
     pub fn with_time_to_live(path: PathBuf, ttl: Duration) -> Result<Self, Error> {
-        let db = Connection::open(path.as_path())?;
+        Self::with_failure_policy(path, ttl, CacheFailure::Error)
+    }
 
-        db.execute(
-            "CREATE TABLE IF NOT EXISTS items (
-            id      TEXT PRIMARY KEY,
-            expires TEXT NOT NULL,
-            data    BLOB NOT NULL
-        )",
-            (),
-        )?;
+    /// Create a new cache, resetting its stored data if the on-disk schema
+    /// version doesn't match `schema_version`
+    ///
+    /// The cache's payloads are `bitcode`-serialized, so a change in `T`'s
+    /// layout can make old blobs deserialize into garbage. Bump
+    /// `schema_version` whenever `T` changes shape; on mismatch the `items`
+    /// table is wiped and re-created before the cache is handed back.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the SQLite database file
+    /// * `ttl` - Time-to-live for cache entries
+    /// * `on_failure` - What to do if the database can't be opened or the schema can't be created
+    /// * `schema_version` - Version of `T`'s on-disk layout; a mismatch with the stored version resets the cache
+    ///
+    /// # Returns
+    /// A new cache instance
+    ///
+    /// # Errors
+    /// Returns an error if the database connection cannot be established and `on_failure` is `CacheFailure::Error`
+    ///
+    /// # Example
+    /// ```rust
+    /// use pond_cache::{Cache, CacheFailure};
+    /// use std::path::PathBuf;
+    /// use chrono::Duration;
+    ///
+    /// let cache: Cache<String> = Cache::with_schema_version(
+    ///     PathBuf::from("cache.db"),
+    ///     Duration::minutes(5),
+    ///     CacheFailure::Error,
+    ///     1,
+    /// )
+    /// .expect("Failed to create cache");
+    /// ```
+    pub fn with_schema_version(
+        path: PathBuf,
+        ttl: Duration,
+        on_failure: CacheFailure,
+        schema_version: u32,
+    ) -> Result<Self, Error> {
+        Self::with_lru_capacity(path, ttl, on_failure, schema_version, None)
+    }
 
-        db.close().expect("Failed to close database connection");
+    /// Create a new cache with a bounded in-memory LRU layer in front of the
+    /// SQLite store
+    ///
+    /// `get` checks the LRU before touching the database, promoting rows it
+    /// finds there on a miss; `store` writes through to both. Entries carry
+    /// their expiration so the LRU honors TTL without a DB round-trip.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the SQLite database file
+    /// * `ttl` - Time-to-live for cache entries
+    /// * `on_failure` - What to do if the database can't be opened or the schema can't be created
+    /// * `schema_version` - Version of `T`'s on-disk layout; a mismatch with the stored version resets the cache
+    /// * `lru_capacity` - Number of entries to keep in the in-memory layer; `None` disables it
+    ///
+    /// # Returns
+    /// A new cache instance
+    ///
+    /// # Errors
+    /// Returns an error if the database connection cannot be established and `on_failure` is `CacheFailure::Error`
+    ///
+    /// # Example
+    /// ```rust
+    /// use pond_cache::{Cache, CacheFailure};
+    /// use std::path::PathBuf;
+    /// use chrono::Duration;
+    ///
+    /// let cache: Cache<String> = Cache::with_lru_capacity(
+    ///     PathBuf::from("cache.db"),
+    ///     Duration::minutes(5),
+    ///     CacheFailure::Error,
+    ///     1,
+    ///     Some(1024),
+    /// )
+    /// .expect("Failed to create cache");
+    /// ```
+    pub fn with_lru_capacity(
+        path: PathBuf,
+        ttl: Duration,
+        on_failure: CacheFailure,
+        schema_version: u32,
+        lru_capacity: Option<usize>,
+    ) -> Result<Self, Error> {
+        let lru = lru_capacity
+            .and_then(NonZeroUsize::new)
+            .map(|cap| Mutex::new(LruCache::new(cap)));
+
+        match Self::connect(path.as_path(), schema_version) {
+            Ok(db) => Ok(Self {
+                path,
+                ttl,
+                conn: Some(Mutex::new(db)),
+                lru,
+                data: std::marker::PhantomData,
+            }),
+            Err(err) => match on_failure {
+                CacheFailure::Error => Err(err),
+                CacheFailure::InMemory => {
+                    let db = Connection::open_in_memory()?;
+                    db.pragma_update(None, "synchronous", "NORMAL")?;
+                    db.execute(CREATE_TABLE_SQL, ())?;
+                    db.pragma_update(None, "user_version", schema_version)?;
+
+                    Ok(Self {
+                        path,
+                        ttl,
+                        conn: Some(Mutex::new(db)),
+                        lru,
+                        data: std::marker::PhantomData,
+                    })
+                }
+                CacheFailure::Blackhole => Ok(Self {
+                    path,
+                    ttl,
+                    conn: None,
+                    // No backing store to front, so there's nothing to cache.
+                    lru: None,
+                    data: std::marker::PhantomData,
+                }),
+            },
+        }
+    }
 
-        Ok(Self {
-            path,
-            ttl,
-            data: std::marker::PhantomData,
-        })
+    /// Create a new cache with a custom time-to-live and a policy for what
+    /// to do if the SQLite connection cannot be established
+    ///
+    /// # Arguments
+    /// * `path` - Path to the SQLite database file
+    /// * `ttl` - Time-to-live for cache entries
+    /// * `on_failure` - What to do if the database can't be opened or the schema can't be created
+    ///
+    /// # Returns
+    /// A new cache instance
+    ///
+    /// # Errors
+    /// Returns an error if the database connection cannot be established and `on_failure` is `CacheFailure::Error`
+    ///
+    /// # Example
+    /// ```rust
+    /// use pond_cache::{Cache, CacheFailure};
+    /// use std::path::PathBuf;
+    /// use chrono::Duration;
+    ///
+    /// let cache: Cache<String> = Cache::with_failure_policy(
+    ///     PathBuf::from("cache.db"),
+    ///     Duration::minutes(5),
+    ///     CacheFailure::InMemory,
+    /// )
+    /// .expect("Failed to create cache");
+    /// ```
+    pub fn with_failure_policy(
+        path: PathBuf,
+        ttl: Duration,
+        on_failure: CacheFailure,
+    ) -> Result<Self, Error> {
+        Self::with_schema_version(path, ttl, on_failure, 0)
+    }
+
+    fn connect(path: &std::path::Path, schema_version: u32) -> Result<Connection, Error> {
+        let db = Connection::open(path)?;
+
+        db.pragma_update(None, "journal_mode", "WAL")?;
+        db.pragma_update(None, "synchronous", "NORMAL")?;
+
+        let stored_version: u32 = db.pragma_query_value(None, "user_version", |row| row.get(0))?;
+        if stored_version != schema_version {
+            db.execute("DROP TABLE IF EXISTS items", ())?;
+        }
+
+        db.execute(CREATE_TABLE_SQL, ())?;
+        db.pragma_update(None, "user_version", schema_version)?;
+
+        Ok(db)
+    }
+
+    /// Path to the SQLite database backing this cache, as passed to the
+    /// constructor. Still returned under `CacheFailure::InMemory` fallback,
+    /// even though the in-memory connection doesn't actually live there.
+    pub fn path(&self) -> &Path {
+        &self.path
     }
 
     /// Retrieve a value from the cache
@@ -112,47 +353,76 @@ impl<T: Serialize + DeserializeOwned + Clone> Cache<T> {
     /// let key = "key";
     /// let value: Option<String> = cache.get(key).expect("Failed to get value");
     /// ```
-    pub fn get<K: Hash>(&self, key: K) -> Result<Option<T>, Error> {
-        let db = Connection::open(self.path.as_path())?;
+    pub fn get<K: CacheKey>(&self, key: K) -> Result<Option<T>, Error> {
+        let key_bytes = key.key_bytes();
+
+        if let Some(lru) = &self.lru {
+            let mut lru = lru.lock().expect("Cache LRU mutex poisoned");
+            match lru.get(&key_bytes) {
+                Some(entry) if entry.expiration >= Utc::now() => {
+                    return Ok(Some(entry.value.clone()));
+                }
+                // Expired in the LRU; fall through and let the DB confirm
+                // whether a newer write replaced it in the meantime.
+                Some(_) => {
+                    lru.pop(&key_bytes);
+                }
+                None => {}
+            }
+        }
 
-        let mut stmt = db.prepare(
-            "SELECT id, expires, data
+        let Some(conn) = &self.conn else {
+            return Ok(None);
+        };
+        let db = conn.lock().expect("Cache connection mutex poisoned");
+
+        let mut stmt = db.prepare_cached(
+            "SELECT expires, data
                 FROM items
-                WHERE id = ?1",
+                WHERE id = ?1 AND key = ?2",
         )?;
 
         let mut hasher = DefaultHasher::new();
         let hash = {
             key.hash(&mut hasher);
-            hasher.finish() as u32
+            hasher.finish()
         };
-        let mut rows = stmt.query([hash]).unwrap();
+        // Filtering on both columns lets a colliding key (same hash,
+        // different bytes) miss here instead of matching someone else's row.
+        let mut rows = stmt.query((hash as i64, &key_bytes)).unwrap();
 
         let Some(row) = rows.next().unwrap() else {
             return Ok(None);
         };
 
         let expires: DateTime<Utc> = row
-            .get::<usize, String>(1)
+            .get::<usize, String>(0)
             .map(|expires_string| {
                 DateTime::parse_from_rfc3339(&expires_string)
                     .unwrap()
                     .with_timezone(&Utc)
             })
             .unwrap();
-        let data: Vec<u8> = row.get(2).unwrap();
-
-        drop(rows);
-        drop(stmt);
-        db.close().expect("Failed to close database connection");
+        let data: Vec<u8> = row.get(1).unwrap();
 
         let data: T = bitcode::deserialize(&data).unwrap();
 
         if expires < Utc::now() {
-            Ok(None)
-        } else {
-            Ok(Some(data))
+            return Ok(None);
+        }
+
+        if let Some(lru) = &self.lru {
+            let mut lru = lru.lock().expect("Cache LRU mutex poisoned");
+            lru.put(
+                key_bytes,
+                LruEntry {
+                    value: data.clone(),
+                    expiration: expires,
+                },
+            );
         }
+
+        Ok(Some(data))
     }
 
     /// Store a value in the cache
@@ -180,7 +450,7 @@ impl<T: Serialize + DeserializeOwned + Clone> Cache<T> {
     /// let value = String::from("value");
     /// cache.store(key, value).expect("Failed to store value");
     /// ```
-    pub fn store<K: Hash>(&self, key: K, value: T) -> Result<(), Error> {
+    pub fn store<K: CacheKey>(&self, key: K, value: T) -> Result<(), Error> {
         self.store_with_expiration(key, value, Utc::now() + self.ttl)
     }
 
@@ -212,7 +482,7 @@ impl<T: Serialize + DeserializeOwned + Clone> Cache<T> {
     ///
     /// cache.store_with_expiration(key, value, expiration).expect("Failed to store value");
     /// ```
-    pub fn store_with_expiration<K: Hash>(
+    pub fn store_with_expiration<K: CacheKey>(
         &self,
         key: K,
         value: T,
@@ -221,27 +491,39 @@ impl<T: Serialize + DeserializeOwned + Clone> Cache<T> {
         let mut hasher = DefaultHasher::new();
         let hash = {
             key.hash(&mut hasher);
-            hasher.finish() as u32
+            hasher.finish()
         };
+        let key_bytes = key.key_bytes();
 
-        let value = CacheEntry {
+        let entry = CacheEntry {
             key: hash,
-            value,
+            key_bytes: key_bytes.clone(),
+            value: value.clone(),
             expiration,
         };
 
-        let db = Connection::open(self.path.as_path())?;
+        let Some(conn) = &self.conn else {
+            return Ok(());
+        };
+        let db = conn.lock().expect("Cache connection mutex poisoned");
 
-        db.execute(
-            "INSERT OR REPLACE INTO items (id, expires, data) VALUES (?1, ?2, ?3);",
-            (
-                &value.key.to_string(),
-                &value.expiration.to_rfc3339(),
-                &bitcode::serialize(&value.value).unwrap(),
-            ),
+        let mut stmt = db.prepare_cached(
+            "INSERT OR REPLACE INTO items (id, key, expires, data) VALUES (?1, ?2, ?3, ?4);",
         )?;
 
-        db.close().expect("Failed to close database connection");
+        stmt.execute((
+            entry.key as i64,
+            &entry.key_bytes,
+            &entry.expiration.to_rfc3339(),
+            &bitcode::serialize(&entry.value).unwrap(),
+        ))?;
+
+        // Only promote into the LRU once the write-through to the backing
+        // store has actually succeeded.
+        if let Some(lru) = &self.lru {
+            let mut lru = lru.lock().expect("Cache LRU mutex poisoned");
+            lru.put(key_bytes, LruEntry { value, expiration });
+        }
 
         Ok(())
     }
@@ -265,17 +547,327 @@ impl<T: Serialize + DeserializeOwned + Clone> Cache<T> {
     /// cache.clean().expect("Failed to clean cache");
     /// ```
     pub fn clean(&self) -> Result<(), Error> {
-        let db = Connection::open(self.path.as_path())?;
+        let Some(conn) = &self.conn else {
+            return Ok(());
+        };
+        let db = conn.lock().expect("Cache connection mutex poisoned");
 
-        db.execute(
-            "DELETE FROM items WHERE expires < ?1;",
-            (&Utc::now().to_rfc3339(),),
-        )?;
+        let mut stmt = db.prepare_cached("DELETE FROM items WHERE expires < ?1;")?;
 
-        db.close().expect("Failed to close database connection");
+        stmt.execute((&Utc::now().to_rfc3339(),))?;
 
         Ok(())
     }
+
+    /// Store many values in the cache inside a single transaction
+    /// Each value is stored with the cache's time-to-live
+    ///
+    /// # Arguments
+    /// * `entries` - Key/value pairs to store
+    ///
+    /// # Returns
+    /// Ok if all values were stored successfully
+    /// Err if the values could not be stored, in which case none of them are
+    ///
+    /// # Errors
+    /// Returns an error if the database connection cannot be established
+    ///
+    /// # Example
+    /// ```rust
+    /// use pond_cache::Cache;
+    /// use std::path::PathBuf;
+    ///
+    /// let cache: Cache<String> = Cache::new(PathBuf::from("cache.db")).expect("Failed to create cache");
+    /// cache
+    ///     .store_many([("a", String::from("1")), ("b", String::from("2"))])
+    ///     .expect("Failed to store values");
+    /// ```
+    pub fn store_many<K: CacheKey>(
+        &self,
+        entries: impl IntoIterator<Item = (K, T)>,
+    ) -> Result<(), Error> {
+        let expiration = Utc::now() + self.ttl;
+        let entries: Vec<(K, T)> = entries.into_iter().collect();
+
+        let Some(conn) = &self.conn else {
+            return Ok(());
+        };
+        let mut db = conn.lock().expect("Cache connection mutex poisoned");
+
+        let tx = db.transaction()?;
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT OR REPLACE INTO items (id, key, expires, data) VALUES (?1, ?2, ?3, ?4);",
+            )?;
+
+            for (key, value) in &entries {
+                let mut hasher = DefaultHasher::new();
+                key.hash(&mut hasher);
+                let hash = hasher.finish();
+
+                stmt.execute((
+                    hash as i64,
+                    &key.key_bytes(),
+                    &expiration.to_rfc3339(),
+                    &bitcode::serialize(value).unwrap(),
+                ))?;
+            }
+        }
+        tx.commit()?;
+
+        // Only promote into the LRU once the whole transaction has committed,
+        // so a failed batch never leaves stale entries behind in the LRU.
+        if let Some(lru) = &self.lru {
+            let mut lru = lru.lock().expect("Cache LRU mutex poisoned");
+            for (key, value) in &entries {
+                lru.put(
+                    key.key_bytes(),
+                    LruEntry {
+                        value: value.clone(),
+                        expiration,
+                    },
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Retrieve many values from the cache, looking up keys that miss the
+    /// LRU with one `WHERE id IN (...)` query per chunk of up to
+    /// `GET_MANY_CHUNK_SIZE` keys
+    ///
+    /// # Arguments
+    /// * `keys` - Keys to retrieve the values for
+    ///
+    /// # Returns
+    /// A `Vec` the same length as `keys`, in the same order, with `None`
+    /// wherever a key does not exist or has expired
+    ///
+    /// # Errors
+    /// Returns an error if the database connection cannot be established
+    ///
+    /// # Example
+    /// ```rust
+    /// use pond_cache::Cache;
+    /// use std::path::PathBuf;
+    ///
+    /// let cache: Cache<String> = Cache::new(PathBuf::from("cache.db")).expect("Failed to create cache");
+    /// let results = cache.get_many(&["a", "b"]).expect("Failed to get values");
+    /// ```
+    pub fn get_many<K: CacheKey>(&self, keys: &[K]) -> Result<Vec<Option<T>>, Error> {
+        let mut results: Vec<Option<T>> = vec![None; keys.len()];
+        let mut pending = Vec::new();
+
+        if let Some(lru) = &self.lru {
+            let mut lru = lru.lock().expect("Cache LRU mutex poisoned");
+            for (i, key) in keys.iter().enumerate() {
+                let key_bytes = key.key_bytes();
+                match lru.get(&key_bytes) {
+                    Some(entry) if entry.expiration >= Utc::now() => {
+                        results[i] = Some(entry.value.clone());
+                    }
+                    Some(_) => {
+                        lru.pop(&key_bytes);
+                        pending.push(i);
+                    }
+                    None => pending.push(i),
+                }
+            }
+        } else {
+            pending.extend(0..keys.len());
+        }
+
+        if pending.is_empty() {
+            return Ok(results);
+        }
+
+        let Some(conn) = &self.conn else {
+            return Ok(results);
+        };
+        let db = conn.lock().expect("Cache connection mutex poisoned");
+
+        let pending_hashes: Vec<(usize, i64)> = pending
+            .iter()
+            .map(|&i| {
+                let mut hasher = DefaultHasher::new();
+                keys[i].hash(&mut hasher);
+                (i, hasher.finish() as i64)
+            })
+            .collect();
+
+        // `id` isn't unique (two keys can collide on their hash), so a chunk
+        // can return more than one row per id; keep every candidate and pick
+        // the one whose key bytes actually match below.
+        let mut by_id: std::collections::HashMap<i64, Vec<StoredRow>> =
+            std::collections::HashMap::new();
+
+        // Batch the `IN (...)` lookup so a large `get_many` call can't blow
+        // past SQLite's bound parameter limit.
+        for chunk in pending_hashes.chunks(GET_MANY_CHUNK_SIZE) {
+            let placeholders = chunk.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+            let mut stmt = db.prepare_cached(&format!(
+                "SELECT id, key, expires, data FROM items WHERE id IN ({placeholders})"
+            ))?;
+
+            let mut rows = stmt.query(rusqlite::params_from_iter(
+                chunk.iter().map(|&(_, hash)| hash),
+            ))?;
+            while let Some(row) = rows.next()? {
+                let id: i64 = row.get(0)?;
+                let key_bytes: Vec<u8> = row.get(1)?;
+                let expires: DateTime<Utc> = row
+                    .get::<usize, String>(2)
+                    .map(|expires_string| {
+                        DateTime::parse_from_rfc3339(&expires_string)
+                            .unwrap()
+                            .with_timezone(&Utc)
+                    })
+                    .unwrap();
+                let data: Vec<u8> = row.get(3)?;
+
+                by_id.entry(id).or_default().push(StoredRow {
+                    key_bytes,
+                    expires,
+                    data,
+                });
+            }
+        }
+
+        for &(i, hash) in &pending_hashes {
+            let key_bytes = keys[i].key_bytes();
+            let Some(candidates) = by_id.get(&hash) else {
+                continue;
+            };
+            let Some(StoredRow { expires, data, .. }) =
+                candidates.iter().find(|row| row.key_bytes == key_bytes)
+            else {
+                continue;
+            };
+            if *expires < Utc::now() {
+                continue;
+            }
+
+            let value: T = bitcode::deserialize(data).unwrap();
+
+            if let Some(lru) = &self.lru {
+                let mut lru = lru.lock().expect("Cache LRU mutex poisoned");
+                lru.put(
+                    key_bytes,
+                    LruEntry {
+                        value: value.clone(),
+                        expiration: *expires,
+                    },
+                );
+            }
+
+            results[i] = Some(value);
+        }
+
+        Ok(results)
+    }
+}
+
+/// Async variants of [`Cache`]'s methods, gated behind the `async` feature
+///
+/// SQLite access is still blocking under the hood, so each method offloads
+/// the underlying sync call to [`tokio::task::spawn_blocking`] instead of
+/// running it on the async executor. The sync API on [`Cache`] is untouched;
+/// these methods just need `self` behind an `Arc` so the blocking task can
+/// own a handle to the cache for its lifetime.
+#[cfg(feature = "async")]
+impl<T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static> Cache<T> {
+    /// Retrieve a value from the cache without blocking the async executor
+    ///
+    /// # Arguments
+    /// * `key` - Key to retrieve the value for
+    ///
+    /// # Errors
+    /// Returns an error if the database connection cannot be established
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use pond_cache::Cache;
+    /// use std::path::PathBuf;
+    /// use std::sync::Arc;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let cache: Arc<Cache<String>> =
+    ///     Arc::new(Cache::new(PathBuf::from("cache.db")).expect("Failed to create cache"));
+    /// let value: Option<String> = cache.get_async("key").await.expect("Failed to get value");
+    /// # }
+    /// ```
+    pub async fn get_async<K>(self: &std::sync::Arc<Self>, key: K) -> Result<Option<T>, Error>
+    where
+        K: CacheKey + Send + 'static,
+    {
+        let this = std::sync::Arc::clone(self);
+        tokio::task::spawn_blocking(move || this.get(key))
+            .await
+            .expect("Cache blocking task panicked")
+    }
+
+    /// Store a value in the cache without blocking the async executor
+    /// The value will be stored with the cache's time-to-live
+    ///
+    /// # Arguments
+    /// * `key` - Key to store the value under
+    /// * `value` - Value to store
+    ///
+    /// # Errors
+    /// Returns an error if the database connection cannot be established
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use pond_cache::Cache;
+    /// use std::path::PathBuf;
+    /// use std::sync::Arc;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let cache: Arc<Cache<String>> =
+    ///     Arc::new(Cache::new(PathBuf::from("cache.db")).expect("Failed to create cache"));
+    /// cache
+    ///     .store_async("key", String::from("value"))
+    ///     .await
+    ///     .expect("Failed to store value");
+    /// # }
+    /// ```
+    pub async fn store_async<K>(self: &std::sync::Arc<Self>, key: K, value: T) -> Result<(), Error>
+    where
+        K: CacheKey + Send + 'static,
+    {
+        let this = std::sync::Arc::clone(self);
+        tokio::task::spawn_blocking(move || this.store(key, value))
+            .await
+            .expect("Cache blocking task panicked")
+    }
+
+    /// Clean up the cache by removing expired entries without blocking the async executor
+    ///
+    /// # Errors
+    /// Returns an error if the database connection cannot be established
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use pond_cache::Cache;
+    /// use std::path::PathBuf;
+    /// use std::sync::Arc;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let cache: Arc<Cache<String>> =
+    ///     Arc::new(Cache::new(PathBuf::from("cache.db")).expect("Failed to create cache"));
+    /// cache.clean_async().await.expect("Failed to clean cache");
+    /// # }
+    /// ```
+    pub async fn clean_async(self: &std::sync::Arc<Self>) -> Result<(), Error> {
+        let this = std::sync::Arc::clone(self);
+        tokio::task::spawn_blocking(move || this.clean())
+            .await
+            .expect("Cache blocking task panicked")
+    }
 }
 
 #[cfg(test)]
@@ -301,14 +893,14 @@ mod tests {
         let mut hasher = DefaultHasher::new();
         let hash = {
             key.hash(&mut hasher);
-            hasher.finish() as u32
+            hasher.finish()
         };
 
         let db = Connection::open(path.as_path()).unwrap();
 
         db.execute(
-            "INSERT OR REPLACE INTO items (id, expires, data) VALUES (?1, ?2, ?3);",
-            (hash, &expires.to_rfc3339(), &value),
+            "INSERT OR REPLACE INTO items (id, key, expires, data) VALUES (?1, ?2, ?3, ?4);",
+            (hash as i64, key.key_bytes(), &expires.to_rfc3339(), &value),
         )
         .unwrap();
 
@@ -323,7 +915,7 @@ mod tests {
         let db = Connection::open(path.as_path())?;
 
         let mut stmt = db.prepare(
-            "SELECT id, expires, data
+            "SELECT key, expires, data
                 FROM items
                 WHERE id = ?1",
         )?;
@@ -331,15 +923,16 @@ mod tests {
         let mut hasher = DefaultHasher::new();
         let hash = {
             key.hash(&mut hasher);
-            hasher.finish() as u32
+            hasher.finish()
         };
 
-        let mut rows = stmt.query([hash]).unwrap();
+        let mut rows = stmt.query([hash as i64]).unwrap();
 
         let Some(row) = rows.next().unwrap() else {
             return Ok(None);
         };
 
+        let key_bytes: Vec<u8> = row.get(0).unwrap();
         let expires: DateTime<Utc> = row
             .get::<usize, String>(1)
             .map(|expires_string| {
@@ -358,6 +951,7 @@ mod tests {
 
         Ok(Some(CacheEntry {
             key: hash,
+            key_bytes,
             value: data,
             expiration: expires,
         }))
@@ -504,6 +1098,41 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    #[test]
+    fn test_get_hash_collision_returns_none() {
+        let filename = std::env::temp_dir().join(format!(
+            "pond-test-{}-{}.sqlite",
+            Uuid::new_v4(),
+            rand::random::<u8>()
+        ));
+
+        let cache = Cache::new(filename.clone()).unwrap();
+
+        let key = "key";
+        let value = String::from("Hello, world!");
+
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let db = Connection::open(filename.as_path()).unwrap();
+        db.execute(
+            "INSERT OR REPLACE INTO items (id, key, expires, data) VALUES (?1, ?2, ?3, ?4);",
+            (
+                hash as i64,
+                b"a different key".as_slice(),
+                &(Utc::now() + Duration::minutes(5)).to_rfc3339(),
+                &bitcode::serialize(&value).unwrap(),
+            ),
+        )
+        .unwrap();
+        db.close().unwrap();
+
+        let result: Option<String> = cache.get(key).unwrap();
+
+        assert_eq!(result, None);
+    }
+
     #[test]
     fn test_invalid_path() {
         let cache: Result<Cache<String>, Error> =
@@ -512,6 +1141,165 @@ mod tests {
         assert!(cache.is_err());
     }
 
+    #[test]
+    fn test_failure_policy_in_memory() {
+        let cache: Cache<String> = Cache::with_failure_policy(
+            PathBuf::from("invalid/path/db.sqlite"),
+            Duration::minutes(10),
+            CacheFailure::InMemory,
+        )
+        .unwrap();
+
+        let key = Uuid::new_v4();
+        let value = String::from("Hello, world!");
+
+        cache.store(key, value.clone()).unwrap();
+        let result: Option<_> = cache.get(key).unwrap();
+
+        assert_eq!(result, Some(value));
+    }
+
+    #[test]
+    fn test_failure_policy_blackhole() {
+        let cache: Cache<String> = Cache::with_failure_policy(
+            PathBuf::from("invalid/path/db.sqlite"),
+            Duration::minutes(10),
+            CacheFailure::Blackhole,
+        )
+        .unwrap();
+
+        let key = Uuid::new_v4();
+        let value = String::from("Hello, world!");
+
+        cache.store(key, value).unwrap();
+        let result: Option<String> = cache.get(key).unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_lru_hit_avoids_db() {
+        let filename = std::env::temp_dir().join(format!(
+            "pond-test-{}-{}.sqlite",
+            Uuid::new_v4(),
+            rand::random::<u8>()
+        ));
+
+        let cache: Cache<String> = Cache::with_lru_capacity(
+            filename.clone(),
+            Duration::minutes(10),
+            CacheFailure::Error,
+            0,
+            Some(8),
+        )
+        .unwrap();
+
+        let key = Uuid::new_v4();
+        let value = String::from("Hello, world!");
+
+        cache.store(key, value.clone()).unwrap();
+
+        // Remove the backing row directly; a hit should still come from the LRU.
+        let db = Connection::open(filename.as_path()).unwrap();
+        db.execute("DELETE FROM items", ()).unwrap();
+        db.close().unwrap();
+
+        let result: Option<_> = cache.get(key).unwrap();
+        assert_eq!(result, Some(value));
+    }
+
+    #[test]
+    fn test_lru_evicts_past_capacity() {
+        let filename = std::env::temp_dir().join(format!(
+            "pond-test-{}-{}.sqlite",
+            Uuid::new_v4(),
+            rand::random::<u8>()
+        ));
+
+        let cache: Cache<String> = Cache::with_lru_capacity(
+            filename.clone(),
+            Duration::minutes(10),
+            CacheFailure::Error,
+            0,
+            Some(1),
+        )
+        .unwrap();
+
+        let first_key = Uuid::new_v4();
+        let second_key = Uuid::new_v4();
+
+        cache.store(first_key, String::from("first")).unwrap();
+        cache.store(second_key, String::from("second")).unwrap();
+
+        // Remove the backing rows directly so only the LRU can answer `get`.
+        let db = Connection::open(filename.as_path()).unwrap();
+        db.execute("DELETE FROM items", ()).unwrap();
+        db.close().unwrap();
+
+        // The first entry was evicted from the LRU by the second, so it's gone.
+        let first_result: Option<String> = cache.get(first_key).unwrap();
+        assert_eq!(first_result, None);
+
+        let second_result: Option<String> = cache.get(second_key).unwrap();
+        assert_eq!(second_result, Some(String::from("second")));
+    }
+
+    #[test]
+    fn test_schema_version_unchanged_keeps_data() {
+        let filename = std::env::temp_dir().join(format!(
+            "pond-test-{}-{}.sqlite",
+            Uuid::new_v4(),
+            rand::random::<u8>()
+        ));
+
+        let cache: Cache<String> = Cache::with_schema_version(
+            filename.clone(),
+            Duration::minutes(10),
+            CacheFailure::Error,
+            1,
+        )
+        .unwrap();
+
+        let key = Uuid::new_v4();
+        let value = String::from("Hello, world!");
+        cache.store(key, value.clone()).unwrap();
+
+        let cache: Cache<String> =
+            Cache::with_schema_version(filename, Duration::minutes(10), CacheFailure::Error, 1)
+                .unwrap();
+        let result: Option<_> = cache.get(key).unwrap();
+
+        assert_eq!(result, Some(value));
+    }
+
+    #[test]
+    fn test_schema_version_change_resets_data() {
+        let filename = std::env::temp_dir().join(format!(
+            "pond-test-{}-{}.sqlite",
+            Uuid::new_v4(),
+            rand::random::<u8>()
+        ));
+
+        let cache: Cache<String> = Cache::with_schema_version(
+            filename.clone(),
+            Duration::minutes(10),
+            CacheFailure::Error,
+            1,
+        )
+        .unwrap();
+
+        let key = Uuid::new_v4();
+        let value = String::from("Hello, world!");
+        cache.store(key, value).unwrap();
+
+        let cache: Cache<String> =
+            Cache::with_schema_version(filename, Duration::minutes(10), CacheFailure::Error, 2)
+                .unwrap();
+        let result: Option<String> = cache.get(key).unwrap();
+
+        assert_eq!(result, None);
+    }
+
     #[test]
     fn test_clean() {
         let filename = std::env::temp_dir().join(format!(
@@ -584,4 +1372,113 @@ mod tests {
             panic!("Expected result to be Some");
         }
     }
+
+    #[test]
+    fn test_store_many_get_many() {
+        let filename = std::env::temp_dir().join(format!(
+            "pond-test-{}-{}.sqlite",
+            Uuid::new_v4(),
+            rand::random::<u8>()
+        ));
+
+        let cache: Cache<String> = Cache::new(filename).unwrap();
+
+        let keys = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        let values = [
+            String::from("one"),
+            String::from("two"),
+            String::from("three"),
+        ];
+
+        cache
+            .store_many(keys.into_iter().zip(values.clone()))
+            .unwrap();
+
+        let results = cache.get_many(&keys).unwrap();
+
+        assert_eq!(results, values.into_iter().map(Some).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_get_many_missing_and_expired() {
+        let filename = std::env::temp_dir().join(format!(
+            "pond-test-{}-{}.sqlite",
+            Uuid::new_v4(),
+            rand::random::<u8>()
+        ));
+
+        let cache: Cache<String> = Cache::new(filename).unwrap();
+
+        let present_key = Uuid::new_v4();
+        let expired_key = Uuid::new_v4();
+        let missing_key = Uuid::new_v4();
+
+        cache
+            .store(present_key, String::from("still here"))
+            .unwrap();
+        cache
+            .store_with_expiration(
+                expired_key,
+                String::from("gone"),
+                Utc::now() - Duration::minutes(5),
+            )
+            .unwrap();
+
+        let results = cache
+            .get_many(&[present_key, expired_key, missing_key])
+            .unwrap();
+
+        assert_eq!(results, vec![Some(String::from("still here")), None, None]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_store_get_async() {
+        let filename = std::env::temp_dir().join(format!(
+            "pond-test-{}-{}.sqlite",
+            Uuid::new_v4(),
+            rand::random::<u8>()
+        ));
+
+        let cache: std::sync::Arc<Cache<String>> =
+            std::sync::Arc::new(Cache::new(filename).unwrap());
+
+        let key = Uuid::new_v4();
+        let value = String::from("Hello, world!");
+
+        cache.store_async(key, value.clone()).await.unwrap();
+        let result = cache.get_async(key).await.unwrap();
+
+        assert_eq!(result, Some(value));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_clean_async() {
+        let filename = std::env::temp_dir().join(format!(
+            "pond-test-{}-{}.sqlite",
+            Uuid::new_v4(),
+            rand::random::<u8>()
+        ));
+
+        let cache: std::sync::Arc<Cache<String>> = std::sync::Arc::new(
+            Cache::with_time_to_live(filename.clone(), Duration::minutes(5)).unwrap(),
+        );
+
+        let key = Uuid::new_v4().to_string();
+        let value = String::from("Hello, world!");
+
+        store_manual(
+            filename.clone(),
+            key.clone(),
+            bitcode::serialize(&value).unwrap(),
+            Utc::now() - Duration::minutes(5),
+        )
+        .unwrap();
+
+        cache.clean_async().await.unwrap();
+
+        let result: Option<CacheEntry<String>> = get_manual(filename, key).unwrap();
+        assert!(result.is_none());
+    }
 }